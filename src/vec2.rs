@@ -1,25 +1,113 @@
 use std::{
     num::NonZeroU32,
     ops::{Index, IndexMut},
+    pin::Pin,
 };
 
 /// A 2D vector-like data structure that allocates memory in chunks.
+///
+/// # Pointer stability
+///
+/// Each chunk is a `Vec<T>` allocated once with `Vec::with_capacity(chunk_size)` and is
+/// never pushed into past that capacity, so it never reallocates. This means the address
+/// of an element already stored in a `Vec2` never changes for the life of the `Vec2`,
+/// unlike `std::Vec`, which may move every element on reallocation. `push_get`,
+/// `get_pin`, and `get_pin_mut` expose this guarantee.
+///
+/// This guarantee is about addresses, not values: `remove`, `insert`, and `swap_remove`
+/// shift the logical sequence by swapping values between fixed addresses, so an address
+/// you pinned earlier can end up holding a different logical element after one of these
+/// calls. That's fine for indexing, but it breaks arena- or intrusive-pointer-style uses
+/// where a held `Pin`/reference is expected to keep denoting the same *value* — those
+/// uses should stick to `push`/`push_get`/`push_front`/`pop`/`pop_front`.
+///
+/// `push_front`/`pop_front` hold to the address guarantee too: the head chunk is stored
+/// back-to-front internally (see `head_chunks`), so prepending and removing from it are
+/// a plain `Vec::push`/`Vec::pop` on already-reserved capacity, never a shift — with one
+/// exception. A chunk that was filled by `push`/`extend` before `push_front` was ever
+/// called is stored front-to-back like any other chunk, so draining it with `pop_front`
+/// still shifts the remaining elements, same as `remove(0)` would. Once that chunk is
+/// fully drained the exception is gone for the rest of the `Vec2`'s life.
+///
+/// Symmetrically, `push`/`pop` shift instead of appending/popping in place whenever the
+/// last chunk is still back-to-front — a `Vec2` built entirely out of reserved head
+/// capacity via `push_front`, with `push`/`extend` never yet called. There, the chunk's
+/// logical last slot sits at its physical front, so adding or removing it moves every
+/// other element in that chunk, same as `insert(0, _)`/`remove(0)` would. Once a plain
+/// forward chunk exists at the back this no longer applies.
+///
+/// `split_off` and `append` move whole chunks (addresses and all) when the split/join
+/// point is chunk-aligned and neither side has reserved head room. Otherwise they fall
+/// back to migrating elements one at a time via `pop`/`pop_front`/`push`/`push_front`,
+/// which copies values into the *other* `Vec2`'s storage at new addresses — any address
+/// pinned before such a call should be considered invalidated.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Vec2<T> {
     data: Vec<Vec<T>>,
-    len: usize,        // current number of elements
-    cap: usize,        // allocated capacity
-    chunk_size: usize, // number of elements per chunk
+    len: usize,         // current number of elements
+    cap: usize,         // allocated capacity
+    chunk_size: usize,  // number of elements per chunk
+    head_offset: usize, // leading slots of data[0] reserved for push_front
+    head_chunks: usize, // leading chunks of `data` stored back-to-front (see above)
+}
+
+/// A chunk's row iterator, forward for a plain chunk or reversed for a chunk stored
+/// back-to-front (see `Vec2::head_chunks`).
+enum RowIter<'a, T> {
+    Forward(std::slice::Iter<'a, T>),
+    Reversed(std::iter::Rev<std::slice::Iter<'a, T>>),
+}
+
+impl<'a, T> Iterator for RowIter<'a, T> {
+    type Item = &'a T;
+    fn next(&mut self) -> Option<Self::Item> {
+        match self {
+            RowIter::Forward(it) => it.next(),
+            RowIter::Reversed(it) => it.next(),
+        }
+    }
+}
+
+enum RowIterMut<'a, T> {
+    Forward(std::slice::IterMut<'a, T>),
+    Reversed(std::iter::Rev<std::slice::IterMut<'a, T>>),
+}
+
+impl<'a, T> Iterator for RowIterMut<'a, T> {
+    type Item = &'a mut T;
+    fn next(&mut self) -> Option<Self::Item> {
+        match self {
+            RowIterMut::Forward(it) => it.next(),
+            RowIterMut::Reversed(it) => it.next(),
+        }
+    }
+}
+
+enum RowIntoIter<T> {
+    Forward(std::vec::IntoIter<T>),
+    Reversed(std::iter::Rev<std::vec::IntoIter<T>>),
+}
+
+impl<T> Iterator for RowIntoIter<T> {
+    type Item = T;
+    fn next(&mut self) -> Option<Self::Item> {
+        match self {
+            RowIntoIter::Forward(it) => it.next(),
+            RowIntoIter::Reversed(it) => it.next(),
+        }
+    }
 }
 
 pub struct Iter<'a, T> {
-    iter_row: Option<std::slice::Iter<'a, T>>,
-    iter_rows: std::slice::Iter<'a, Vec<T>>,
+    iter_row: Option<RowIter<'a, T>>,
+    iter_rows: std::iter::Enumerate<std::slice::Iter<'a, Vec<T>>>,
+    head_chunks: usize,
 }
 
 pub struct IterMut<'a, T> {
-    iter_row: Option<std::slice::IterMut<'a, T>>,
-    iter_rows: std::slice::IterMut<'a, Vec<T>>,
+    iter_row: Option<RowIterMut<'a, T>>,
+    iter_rows: std::iter::Enumerate<std::slice::IterMut<'a, Vec<T>>>,
+    head_chunks: usize,
 }
 
 impl<'a, T> Iterator for Iter<'a, T> {
@@ -32,8 +120,12 @@ impl<'a, T> Iterator for Iter<'a, T> {
             }
         }
 
-        if let Some(arr) = self.iter_rows.next() {
-            self.iter_row = Some(arr.iter());
+        if let Some((idx, arr)) = self.iter_rows.next() {
+            self.iter_row = Some(if idx < self.head_chunks {
+                RowIter::Reversed(arr.iter().rev())
+            } else {
+                RowIter::Forward(arr.iter())
+            });
         }
 
         if let Some(ref mut row_iter) = self.iter_row {
@@ -54,8 +146,44 @@ impl<'a, T> Iterator for IterMut<'a, T> {
             }
         }
 
-        if let Some(arr) = self.iter_rows.next() {
-            self.iter_row = Some(arr.iter_mut());
+        if let Some((idx, arr)) = self.iter_rows.next() {
+            self.iter_row = Some(if idx < self.head_chunks {
+                RowIterMut::Reversed(arr.iter_mut().rev())
+            } else {
+                RowIterMut::Forward(arr.iter_mut())
+            });
+        }
+
+        if let Some(ref mut row_iter) = self.iter_row {
+            return row_iter.next();
+        }
+
+        None
+    }
+}
+
+pub struct IntoIter<T> {
+    iter_row: Option<RowIntoIter<T>>,
+    iter_rows: std::iter::Enumerate<std::vec::IntoIter<Vec<T>>>,
+    head_chunks: usize,
+}
+
+impl<T> Iterator for IntoIter<T> {
+    type Item = T;
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(ref mut row_iter) = self.iter_row {
+            let value = row_iter.next();
+            if value.is_some() {
+                return value;
+            }
+        }
+
+        if let Some((idx, arr)) = self.iter_rows.next() {
+            self.iter_row = Some(if idx < self.head_chunks {
+                RowIntoIter::Reversed(arr.into_iter().rev())
+            } else {
+                RowIntoIter::Forward(arr.into_iter())
+            });
         }
 
         if let Some(ref mut row_iter) = self.iter_row {
@@ -75,6 +203,66 @@ impl<T> Vec2<T> {
             len: 0,
             cap: 0,
             chunk_size: chunk_size.get() as usize,
+            head_offset: 0,
+            head_chunks: 0,
+        }
+    }
+
+    /// Creates a new `Vec2` with enough chunks pre-allocated to hold `capacity`
+    /// elements without further allocation.
+    pub fn with_capacity(chunk_size: NonZeroU32, capacity: usize) -> Self {
+        let chunk_size = chunk_size.get() as usize;
+        let num_chunks = capacity.div_ceil(chunk_size);
+        let data = (0..num_chunks)
+            .map(|_| Vec::with_capacity(chunk_size))
+            .collect();
+        Vec2 {
+            data,
+            len: 0,
+            cap: num_chunks * chunk_size,
+            chunk_size,
+            head_offset: 0,
+            head_chunks: 0,
+        }
+    }
+
+    /// Creates a new `Vec2` with the given `chunk_size`, filled from `iter`.
+    ///
+    /// This is the chunk-size-aware counterpart to `FromIterator::from_iter`, which
+    /// cannot take a `chunk_size` parameter.
+    pub fn from_iter_with_chunk_size<I: IntoIterator<Item = T>>(
+        chunk_size: NonZeroU32,
+        iter: I,
+    ) -> Self {
+        let mut vec2 = Vec2::new(chunk_size);
+        vec2.extend(iter);
+        vec2
+    }
+
+    /// Maps a logical index to the `(chunk, pos)` of its physical storage, accounting
+    /// for the reserved front slots tracked by `head_offset` and the back-to-front
+    /// chunks tracked by `head_chunks`.
+    #[inline]
+    fn locate(&self, index: usize) -> (usize, usize) {
+        let head_len = self.chunk_size - self.head_offset;
+        if index < head_len {
+            if self.head_chunks > 0 {
+                (0, head_len - 1 - index)
+            } else {
+                (0, index)
+            }
+        } else {
+            let rest = index - head_len;
+            let chunk = 1 + rest / self.chunk_size;
+            let pos = rest % self.chunk_size;
+            if chunk < self.head_chunks {
+                // Every reversed chunk but the last is packed to `chunk_size`; the last
+                // one may be mid-drain by `pop` (see `pop`'s doc), so use its real
+                // length rather than assuming it's full.
+                (chunk, self.data[chunk].len() - 1 - pos)
+            } else {
+                (chunk, pos)
+            }
         }
     }
 
@@ -99,7 +287,7 @@ impl<T> Vec2<T> {
     /// Returns the capacity of the `Vec2`.
     #[inline]
     pub fn capacity(&self) -> usize {
-        self.cap
+        self.cap - self.head_offset
     }
 
     /// Returns a reference to the element at the specified index, or `None` if out of bounds.
@@ -129,27 +317,182 @@ impl<T> Vec2<T> {
             row.clear();
         }
         self.len = 0;
+        self.head_offset = 0;
+        self.head_chunks = 0;
     }
 
     /// Pushes a new element to the end of the `Vec2`.
+    ///
+    /// See the `# Pointer stability` note on [`Vec2`] for the one case (a `Vec2` that is
+    /// still entirely back-to-front chunks) where this shifts existing elements instead
+    /// of writing into fresh capacity.
     #[inline]
     pub fn push(&mut self, value: T) {
-        if self.len == self.cap {
+        if self.len + self.head_offset == self.cap {
             self.data.push(Vec::with_capacity(self.chunk_size));
             self.cap += self.chunk_size;
         }
-        self.data[self.len / self.chunk_size].push(value);
+        let (chunk, _) = self.locate(self.len);
+        if chunk < self.head_chunks {
+            self.data[chunk].insert(0, value);
+            if chunk == 0 {
+                self.head_offset -= 1;
+            }
+        } else {
+            self.data[chunk].push(value);
+        }
         self.len += 1;
     }
 
-    /// Pops the last element from the `Vec2`.
+    /// Prepends `value` to the front of the `Vec2`, reusing reserved head-chunk room or
+    /// allocating a fresh head chunk when that room is exhausted.
+    ///
+    /// The head chunk is stored back-to-front internally, so this is ordinarily a plain
+    /// `Vec::push` into already-reserved capacity, O(1) worst case rather than just
+    /// amortized — a fresh head chunk is only allocated once every `chunk_size` calls,
+    /// same as `push`. The one exception is reserved room left over in a chunk that was
+    /// built front-to-back by `push`/`extend` and only later partly drained by
+    /// `pop_front` (see that method's doc): that chunk is still stored front-to-back, so
+    /// prepending into its spare capacity shifts its elements like `insert(0, _)` would.
+    pub fn push_front(&mut self, value: T) {
+        if self.head_offset == 0 {
+            self.data.insert(0, Vec::with_capacity(self.chunk_size));
+            self.cap += self.chunk_size;
+            self.head_offset = self.chunk_size;
+            self.head_chunks += 1;
+        }
+        self.head_offset -= 1;
+        if self.head_chunks > 0 {
+            self.data[0].push(value);
+        } else {
+            self.data[0].insert(0, value);
+        }
+        self.len += 1;
+    }
+
+    /// Pushes a new element to the end of the `Vec2` and returns a reference to it.
+    ///
+    /// Thanks to the pointer-stability guarantee documented on [`Vec2`], the element's
+    /// *address* stays valid for the life of the `Vec2`, even as further elements are
+    /// pushed into later chunks. The returned `&mut T` itself, though, borrows `self`
+    /// mutably, so safe code can't hold it across a later `push`/`push_get` call on the
+    /// same `Vec2` — that's a borrow-checker error, same as holding a `&mut T` into a
+    /// `std::Vec` across a `push`. To actually use the address across further pushes,
+    /// cast this reference to a raw pointer and dereference it later behind `unsafe`,
+    /// relying on the address guarantee to make that sound.
+    #[inline]
+    pub fn push_get(&mut self, value: T) -> &mut T {
+        self.push(value);
+        let index = self.len - 1;
+        let (chunk, pos) = self.locate(index);
+        &mut self.data[chunk][pos]
+    }
+
+    /// Returns a pinned reference to the element at the specified index, or `None` if
+    /// out of bounds.
+    ///
+    /// This is sound because of the pointer-stability guarantee documented on [`Vec2`]:
+    /// the element never moves, even across further `push` calls.
     #[inline]
+    pub fn get_pin(&self, index: usize) -> Option<Pin<&T>> {
+        self.get(index)
+            .map(|value| unsafe { Pin::new_unchecked(value) })
+    }
+
+    /// Returns a pinned mutable reference to the element at the specified index, or
+    /// `None` if out of bounds.
+    ///
+    /// This is sound because of the pointer-stability guarantee documented on [`Vec2`]:
+    /// the element never moves, even across further `push` calls.
+    #[inline]
+    pub fn get_pin_mut(&mut self, index: usize) -> Option<Pin<&mut T>> {
+        if index >= self.len {
+            None
+        } else {
+            Some(unsafe { Pin::new_unchecked(&mut self[index]) })
+        }
+    }
+
+    /// Pops the last element from the `Vec2`.
+    ///
+    /// The targeted chunk is always the last one in `data`, so this is ordinarily a
+    /// plain `Vec::pop`. The one exception is a `Vec2` built entirely out of reserved
+    /// head capacity (every chunk still back-to-front, see `head_chunks`, and `push`
+    /// never yet called) — there, the last element sits at that chunk's physical front,
+    /// so removing it shifts the chunk's remaining elements like `remove(0)` would; once
+    /// a forward chunk exists at the back this no longer applies. Once the popped chunk
+    /// empties out it is dropped, same as `pop_front` does for the head chunk.
     pub fn pop(&mut self) -> Option<T> {
         if self.len == 0 {
             return None;
         }
         self.len -= 1;
-        self.data[self.len / self.chunk_size].pop()
+        let (chunk, pos) = self.locate(self.len);
+        let value = if chunk < self.head_chunks {
+            let value = self.data[chunk].remove(pos);
+            if chunk == 0 {
+                // `data[0]` just shrank by one, so it has one more free reserved
+                // slot than before: keep `head_offset` in sync the same way
+                // `pop_front` does, or the next `locate`/`push_front` call will
+                // misjudge how much room is actually left.
+                self.head_offset += 1;
+            }
+            value
+        } else {
+            self.data[chunk]
+                .pop()
+                .expect("locate pointed at chunk's own last element")
+        };
+        if self.data[chunk].is_empty() {
+            self.data.pop();
+            self.cap -= self.chunk_size;
+            if chunk < self.head_chunks {
+                self.head_chunks -= 1;
+            }
+            if chunk == 0 {
+                // Whether `data[0]` was reversed or a plain forward chunk, it's gone
+                // now, so there's no reserved room left to track.
+                self.head_offset = 0;
+            }
+        }
+        Some(value)
+    }
+
+    /// Removes and returns the first element of the `Vec2`, or `None` if it is empty.
+    ///
+    /// Once the head chunk is fully drained it is dropped so the next chunk becomes the
+    /// new head, keeping `push_front`/`pop_front` amortized O(1). If the head chunk was
+    /// built by `push_front` it is stored back-to-front, so this pops from its reserved
+    /// capacity without touching any other element's address; if it was built by
+    /// `push`/`extend` before any `push_front` call, this shifts the remaining elements
+    /// like `remove(0)` instead — see the `# Pointer stability` note on [`Vec2`].
+    pub fn pop_front(&mut self) -> Option<T> {
+        if self.len == 0 {
+            return None;
+        }
+        let value = if self.head_chunks > 0 {
+            self.data[0]
+                .pop()
+                .expect("head_offset tracked data[0] as non-empty")
+        } else {
+            self.data[0].remove(0)
+        };
+        self.head_offset += 1;
+        self.len -= 1;
+        if self.data[0].is_empty() {
+            self.data.remove(0);
+            self.cap -= self.chunk_size;
+            self.head_chunks = self.head_chunks.saturating_sub(1);
+            // The chunk promoted to `data[0]` isn't necessarily full: it may be a
+            // reversed chunk that an earlier `pop` partly drained from the back.
+            // Recompute the reserved-room count from its real length rather than
+            // assuming a fresh, fully-packed chunk.
+            self.head_offset = match self.data.first() {
+                Some(head) => self.chunk_size - head.len(),
+                None => 0,
+            };
+        }
+        Some(value)
     }
 
     /// Swaps the elements at the specified indices.
@@ -162,12 +505,152 @@ impl<T> Vec2<T> {
         }
     }
 
+    /// Splits the `Vec2` in two at `at`, returning a new `Vec2` with the elements
+    /// `[at, len)` and leaving `self` with `[0, at)`.
+    ///
+    /// When `at` lands on a chunk boundary and `self` has no reserved head slots, whole
+    /// chunks are moved into the returned `Vec2` with a single `Vec::split_off` on the
+    /// outer chunk vector, without copying any elements. Otherwise this falls back to
+    /// migrating elements one at a time, which re-establishes the full-chunk invariant
+    /// but copies values into the returned `Vec2`'s storage at new addresses; see the
+    /// `# Pointer stability` note on [`Vec2`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `at > len`.
+    pub fn split_off(&mut self, at: usize) -> Vec2<T> {
+        assert!(at <= self.len, "index out of bounds");
+        if self.head_offset == 0 && at.is_multiple_of(self.chunk_size) {
+            let chunk_index = at / self.chunk_size;
+            let other_head_chunks = self.head_chunks.saturating_sub(chunk_index);
+            self.head_chunks = self.head_chunks.min(chunk_index);
+            let data = self.data.split_off(chunk_index);
+            let new_len = self.len - at;
+            let new_cap = self.cap - at;
+            self.len = at;
+            self.cap = at;
+            Vec2 {
+                data,
+                len: new_len,
+                cap: new_cap,
+                chunk_size: self.chunk_size,
+                head_offset: 0,
+                head_chunks: other_head_chunks,
+            }
+        } else {
+            let mut other = Vec2::new(NonZeroU32::new(self.chunk_size as u32).unwrap());
+            while self.len > at {
+                other.push_front(
+                    self.pop()
+                        .expect("len was just checked to be greater than at"),
+                );
+            }
+            other
+        }
+    }
+
+    /// Moves every element of `other` onto the end of `self`, leaving `other` empty.
+    ///
+    /// When `self`'s last chunk is exactly full and neither side has reserved head
+    /// slots, `other`'s chunks are moved directly onto `self`'s outer chunk vector
+    /// without copying any elements. Otherwise this falls back to migrating elements
+    /// one at a time, which re-establishes the full-chunk invariant but copies values
+    /// into `self`'s storage at new addresses; see the `# Pointer stability` note on
+    /// [`Vec2`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `self.chunk_size() != other.chunk_size()`.
+    pub fn append(&mut self, other: &mut Vec2<T>) {
+        assert_eq!(
+            self.chunk_size, other.chunk_size,
+            "chunk_size must match to append"
+        );
+        let self_last_full = match self.data.last() {
+            Some(chunk) => chunk.len() == self.chunk_size,
+            None => true,
+        };
+        if self.head_offset == 0
+            && other.head_offset == 0
+            && other.head_chunks == 0
+            && self_last_full
+        {
+            self.len += other.len;
+            self.cap += other.cap;
+            self.data.append(&mut other.data);
+            other.len = 0;
+            other.cap = 0;
+        } else {
+            while let Some(value) = other.pop_front() {
+                self.push(value);
+            }
+        }
+    }
+
+    /// Removes and returns the element at `index`, shifting every element after it
+    /// one position to the left.
+    ///
+    /// Shifting is done by swapping values between fixed addresses, so addresses held
+    /// past this call (e.g. from `push_get`/`get_pin`) may end up holding a different
+    /// element; see the `# Pointer stability` note on [`Vec2`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds.
+    pub fn remove(&mut self, index: usize) -> T {
+        assert!(index < self.len, "index out of bounds");
+        for i in index..self.len - 1 {
+            self.swap(i, i + 1);
+        }
+        self.pop()
+            .expect("len was just checked to be greater than index")
+    }
+
+    /// Inserts `value` at `index`, shifting every element from `index` onward one
+    /// position to the right, growing a new chunk if the `Vec2` is at capacity.
+    ///
+    /// Shifting is done by swapping values between fixed addresses, so addresses held
+    /// past this call (e.g. from `push_get`/`get_pin`) may end up holding a different
+    /// element; see the `# Pointer stability` note on [`Vec2`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index > len`.
+    pub fn insert(&mut self, index: usize, value: T) {
+        assert!(index <= self.len, "index out of bounds");
+        self.push(value);
+        let mut i = self.len - 1;
+        while i > index {
+            self.swap(i, i - 1);
+            i -= 1;
+        }
+    }
+
+    /// Removes the element at `index` by swapping it with the last element and then
+    /// popping it, avoiding the need to shift any other elements.
+    ///
+    /// This does not preserve ordering but runs in O(1). Like `remove`/`insert`, it
+    /// swaps values between fixed addresses, so addresses held past this call may end
+    /// up holding a different element; see the `# Pointer stability` note on [`Vec2`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds.
+    pub fn swap_remove(&mut self, index: usize) -> T {
+        assert!(index < self.len, "index out of bounds");
+        let last = self.len - 1;
+        self.swap(index, last);
+        self.pop()
+            .expect("len was just checked to be greater than index")
+    }
+
     /// Returns an iterator over the elements of the `Vec2`.
     #[inline]
     pub fn iter(&self) -> Iter<'_, T> {
         Iter {
             iter_row: None,
-            iter_rows: self.data.iter(),
+            iter_rows: self.data.iter().enumerate(),
+            head_chunks: self.head_chunks,
         }
     }
 
@@ -176,7 +659,8 @@ impl<T> Vec2<T> {
     pub fn iter_mut(&mut self) -> IterMut<'_, T> {
         IterMut {
             iter_row: None,
-            iter_rows: self.data.iter_mut(),
+            iter_rows: self.data.iter_mut().enumerate(),
+            head_chunks: self.head_chunks,
         }
     }
 }
@@ -186,14 +670,150 @@ impl<T> Index<usize> for Vec2<T> {
 
     #[inline]
     fn index(&self, index: usize) -> &Self::Output {
-        &self.data[index / self.chunk_size][index % self.chunk_size]
+        let (chunk, pos) = self.locate(index);
+        &self.data[chunk][pos]
     }
 }
 
 impl<T> IndexMut<usize> for Vec2<T> {
     #[inline]
     fn index_mut(&mut self, index: usize) -> &mut Self::Output {
-        &mut self.data[index / self.chunk_size][index % self.chunk_size]
+        let (chunk, pos) = self.locate(index);
+        &mut self.data[chunk][pos]
+    }
+}
+
+/// The default chunk size used by `FromIterator::from_iter`, which cannot take a
+/// `chunk_size` parameter. Use `Vec2::from_iter_with_chunk_size` to pick your own.
+const DEFAULT_CHUNK_SIZE: u32 = 32;
+
+impl<T> FromIterator<T> for Vec2<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let chunk_size =
+            NonZeroU32::new(DEFAULT_CHUNK_SIZE).expect("DEFAULT_CHUNK_SIZE is nonzero");
+        Vec2::from_iter_with_chunk_size(chunk_size, iter)
+    }
+}
+
+impl<T> Extend<T> for Vec2<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for value in iter {
+            self.push(value);
+        }
+    }
+}
+
+impl<T> IntoIterator for Vec2<T> {
+    type Item = T;
+    type IntoIter = IntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        IntoIter {
+            iter_row: None,
+            iter_rows: self.data.into_iter().enumerate(),
+            head_chunks: self.head_chunks,
+        }
+    }
+}
+
+/// `serde` support, enabled by the `serde` cargo feature.
+///
+/// A `Vec2` is serialized as a flat sequence of its logical elements (in `iter` order),
+/// so the wire format does not depend on `chunk_size`. Deserializing rebuilds the
+/// `Vec2` by pushing each element in turn, which re-establishes the full-chunk
+/// invariant regardless of the `chunk_size` used on the serializing side.
+#[cfg(feature = "serde")]
+pub mod serde_impl {
+    use super::Vec2;
+    use serde::{
+        de::{DeserializeSeed, Deserializer, SeqAccess, Visitor},
+        ser::{Serialize, SerializeSeq, Serializer},
+        Deserialize,
+    };
+    use std::{fmt, marker::PhantomData, num::NonZeroU32};
+
+    impl<T: Serialize> Serialize for Vec2<T> {
+        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            let mut seq = serializer.serialize_seq(Some(self.len()))?;
+            for value in self.iter() {
+                seq.serialize_element(value)?;
+            }
+            seq.end()
+        }
+    }
+
+    /// The chunk size used by `Deserialize::deserialize`, which cannot take a
+    /// `chunk_size` parameter. Use `Vec2Seed` to pick your own.
+    const DEFAULT_CHUNK_SIZE: u32 = 32;
+
+    impl<'de, T: Deserialize<'de>> Deserialize<'de> for Vec2<T> {
+        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            let chunk_size =
+                NonZeroU32::new(DEFAULT_CHUNK_SIZE).expect("DEFAULT_CHUNK_SIZE is nonzero");
+            Vec2Seed::new(chunk_size).deserialize(deserializer)
+        }
+    }
+
+    /// A `DeserializeSeed` that rebuilds a `Vec2` with a caller-chosen `chunk_size`,
+    /// the chunk-size-aware counterpart to `Deserialize::deserialize`.
+    pub struct Vec2Seed<T> {
+        chunk_size: NonZeroU32,
+        marker: PhantomData<T>,
+    }
+
+    impl<T> Vec2Seed<T> {
+        /// Creates a seed that deserializes into a `Vec2` with the given `chunk_size`.
+        pub fn new(chunk_size: NonZeroU32) -> Self {
+            Vec2Seed {
+                chunk_size,
+                marker: PhantomData,
+            }
+        }
+    }
+
+    impl<'de, T: Deserialize<'de>> DeserializeSeed<'de> for Vec2Seed<T> {
+        type Value = Vec2<T>;
+
+        fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            struct Vec2Visitor<T> {
+                chunk_size: NonZeroU32,
+                marker: PhantomData<T>,
+            }
+
+            impl<'de, T: Deserialize<'de>> Visitor<'de> for Vec2Visitor<T> {
+                type Value = Vec2<T>;
+
+                fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                    formatter.write_str("a sequence of elements")
+                }
+
+                fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+                where
+                    A: SeqAccess<'de>,
+                {
+                    let mut vec2 =
+                        Vec2::with_capacity(self.chunk_size, seq.size_hint().unwrap_or(0));
+                    while let Some(value) = seq.next_element()? {
+                        vec2.push(value);
+                    }
+                    Ok(vec2)
+                }
+            }
+
+            deserializer.deserialize_seq(Vec2Visitor {
+                chunk_size: self.chunk_size,
+                marker: PhantomData,
+            })
+        }
     }
 }
 
@@ -292,4 +912,437 @@ mod tests {
         assert_eq!(vec2[0], 4);
         assert_eq!(vec2[3], 1);
     }
+
+    #[test]
+    fn test_vec2_push_get() {
+        use std::num::NonZeroU32;
+        let mut vec2 = super::Vec2::new(NonZeroU32::new(3).unwrap());
+        let first = vec2.push_get(1);
+        assert_eq!(*first, 1);
+        *first = 42;
+        assert_eq!(vec2[0], 42);
+    }
+
+    #[test]
+    fn test_vec2_push_get_pointer_stable() {
+        use std::num::NonZeroU32;
+        let mut vec2 = super::Vec2::new(NonZeroU32::new(2).unwrap());
+        let first_ref: &mut i32 = vec2.push_get(0);
+        let first_ptr: *mut i32 = first_ref;
+
+        // Push enough values to force several new chunk allocations.
+        for i in 1..20 {
+            vec2.push(i);
+        }
+
+        assert_eq!(unsafe { *first_ptr }, 0);
+        assert_eq!(vec2[0], 0);
+        assert_eq!(first_ptr, &mut vec2[0] as *mut i32);
+    }
+
+    #[test]
+    fn test_vec2_push_front_pointer_stable() {
+        use std::num::NonZeroU32;
+        let mut vec2 = super::Vec2::new(NonZeroU32::new(2).unwrap());
+        let first_ref: &mut i32 = vec2.push_get(0);
+        let first_ptr: *mut i32 = first_ref;
+
+        // Prepending must not shift the element pushed above to a new address.
+        vec2.push_front(1);
+        vec2.push_front(2);
+
+        assert_eq!(unsafe { *first_ptr }, 0);
+        assert_eq!(first_ptr, &mut vec2[2] as *mut i32);
+    }
+
+    #[test]
+    fn test_vec2_pop_front_pointer_stable() {
+        use std::num::NonZeroU32;
+        let mut vec2 = super::Vec2::new(NonZeroU32::new(3).unwrap());
+        vec2.push_front(2);
+        vec2.push_front(1);
+        vec2.push_front(0);
+        // vec2 is now [0, 1, 2], all within the back-to-front head chunk.
+
+        let second_ptr: *mut i32 = &mut vec2[1];
+        vec2.pop_front();
+
+        // Removing the front element must not shift the remaining ones.
+        assert_eq!(unsafe { *second_ptr }, 1);
+        assert_eq!(second_ptr, &mut vec2[0] as *mut i32);
+    }
+
+    #[test]
+    fn test_vec2_get_pin() {
+        use std::num::NonZeroU32;
+        let mut vec2 = super::Vec2::new(NonZeroU32::new(2).unwrap());
+        vec2.push(10);
+        vec2.push(20);
+        assert_eq!(*vec2.get_pin(0).unwrap(), 10);
+        assert!(vec2.get_pin(2).is_none());
+
+        *vec2.get_pin_mut(1).unwrap() = 25;
+        assert_eq!(vec2[1], 25);
+        assert!(vec2.get_pin_mut(2).is_none());
+    }
+
+    #[test]
+    fn test_vec2_remove_across_chunk_boundary() {
+        use std::num::NonZeroU32;
+        let mut vec2 = super::Vec2::new(NonZeroU32::new(3).unwrap());
+        for i in 0..7 {
+            vec2.push(i);
+        }
+        // Removing index 2 shifts elements from chunk 1 back into chunk 0.
+        assert_eq!(vec2.remove(2), 2);
+        assert_eq!(vec2.len(), 6);
+        let collected: Vec<_> = vec2.iter().cloned().collect();
+        assert_eq!(collected, vec![0, 1, 3, 4, 5, 6]);
+    }
+
+    #[test]
+    fn test_vec2_insert_across_chunk_boundary() {
+        use std::num::NonZeroU32;
+        let mut vec2 = super::Vec2::new(NonZeroU32::new(3).unwrap());
+        for i in [0, 1, 3, 4, 5] {
+            vec2.push(i);
+        }
+        // Inserting at index 2 shifts elements from chunk 0 forward into chunk 1.
+        vec2.insert(2, 2);
+        assert_eq!(vec2.len(), 6);
+        let collected: Vec<_> = vec2.iter().cloned().collect();
+        assert_eq!(collected, vec![0, 1, 2, 3, 4, 5]);
+    }
+
+    #[test]
+    fn test_vec2_insert_grows_capacity() {
+        use std::num::NonZeroU32;
+        let mut vec2 = super::Vec2::new(NonZeroU32::new(2).unwrap());
+        vec2.push(1);
+        vec2.push(2);
+        assert_eq!(vec2.capacity(), 2);
+        vec2.insert(1, 99);
+        assert_eq!(vec2.capacity(), 4);
+        let collected: Vec<_> = vec2.iter().cloned().collect();
+        assert_eq!(collected, vec![1, 99, 2]);
+    }
+
+    #[test]
+    fn test_vec2_swap_remove() {
+        use std::num::NonZeroU32;
+        let mut vec2 = super::Vec2::new(NonZeroU32::new(2).unwrap());
+        for i in 0..5 {
+            vec2.push(i);
+        }
+        assert_eq!(vec2.swap_remove(1), 1);
+        assert_eq!(vec2.len(), 4);
+        let collected: Vec<_> = vec2.iter().cloned().collect();
+        assert_eq!(collected, vec![0, 4, 2, 3]);
+    }
+
+    #[test]
+    fn test_vec2_push_front_pop_front() {
+        use std::num::NonZeroU32;
+        let mut vec2 = super::Vec2::new(NonZeroU32::new(3).unwrap());
+        vec2.push_front(2);
+        vec2.push_front(1);
+        vec2.push_front(0);
+        assert_eq!(vec2.len(), 3);
+        let collected: Vec<_> = vec2.iter().cloned().collect();
+        assert_eq!(collected, vec![0, 1, 2]);
+
+        assert_eq!(vec2.pop_front(), Some(0));
+        assert_eq!(vec2.pop_front(), Some(1));
+        assert_eq!(vec2.len(), 1);
+        assert_eq!(vec2.pop_front(), Some(2));
+        assert_eq!(vec2.pop_front(), None);
+    }
+
+    #[test]
+    fn test_vec2_interleaved_front_and_back() {
+        use std::num::NonZeroU32;
+        let mut vec2 = super::Vec2::new(NonZeroU32::new(2).unwrap());
+        vec2.push(10); // [10]
+        vec2.push_front(5); // [5, 10]
+        vec2.push(15); // [5, 10, 15]
+        vec2.push_front(0); // [0, 5, 10, 15]
+        vec2.push_front(-5); // [-5, 0, 5, 10, 15]
+
+        let collected: Vec<_> = vec2.iter().cloned().collect();
+        assert_eq!(collected, vec![-5, 0, 5, 10, 15]);
+
+        assert_eq!(vec2.pop_front(), Some(-5));
+        assert_eq!(vec2.pop(), Some(15));
+        let collected: Vec<_> = vec2.iter().cloned().collect();
+        assert_eq!(collected, vec![0, 5, 10]);
+    }
+
+    #[test]
+    fn test_vec2_push_front_drops_drained_head_chunk() {
+        use std::num::NonZeroU32;
+        let mut vec2 = super::Vec2::new(NonZeroU32::new(2).unwrap());
+        for i in (0..6).rev() {
+            vec2.push_front(i);
+        }
+        assert_eq!(vec2.capacity(), 6);
+        let collected: Vec<_> = vec2.iter().cloned().collect();
+        assert_eq!(collected, vec![0, 1, 2, 3, 4, 5]);
+
+        assert_eq!(vec2.pop_front(), Some(0));
+        assert_eq!(vec2.pop_front(), Some(1));
+        // The drained head chunk is dropped, so the next chunk becomes the new head.
+        assert_eq!(vec2.pop_front(), Some(2));
+        let collected: Vec<_> = vec2.iter().cloned().collect();
+        assert_eq!(collected, vec![3, 4, 5]);
+    }
+
+    #[test]
+    fn test_vec2_pop_front_drains_only_chunk_then_push() {
+        use std::num::NonZeroU32;
+        let mut vec2 = super::Vec2::new(NonZeroU32::new(4).unwrap());
+        for i in 0..4 {
+            vec2.push(i);
+        }
+        for i in 0..4 {
+            assert_eq!(vec2.pop_front(), Some(i));
+        }
+        assert_eq!(vec2.len(), 0);
+        vec2.push(99);
+        assert_eq!(vec2.pop_front(), Some(99));
+        assert_eq!(vec2.pop_front(), None);
+    }
+
+    #[test]
+    fn test_vec2_pop_front_drains_only_chunk_then_insert() {
+        let mut vec2 = super::Vec2::new(std::num::NonZeroU32::new(4).unwrap());
+        vec2.insert(0, 40);
+        vec2.pop_front();
+        vec2.insert(0, 41);
+        vec2.pop_front();
+        vec2.insert(0, 44);
+        assert_eq!(vec2.len(), 1);
+        let collected: Vec<_> = vec2.iter().cloned().collect();
+        assert_eq!(collected, vec![44]);
+    }
+
+    #[test]
+    fn test_vec2_split_off_aligned() {
+        use std::num::NonZeroU32;
+        let mut vec2 = super::Vec2::new(NonZeroU32::new(3).unwrap());
+        for i in 0..8 {
+            vec2.push(i);
+        }
+        let tail = vec2.split_off(6);
+        assert_eq!(vec2.len(), 6);
+        assert_eq!(vec2.capacity(), 6);
+        assert_eq!(tail.len(), 2);
+        assert_eq!(tail.capacity(), 3);
+        assert_eq!(
+            vec2.iter().cloned().collect::<Vec<_>>(),
+            vec![0, 1, 2, 3, 4, 5]
+        );
+        assert_eq!(tail.iter().cloned().collect::<Vec<_>>(), vec![6, 7]);
+    }
+
+    #[test]
+    fn test_vec2_split_off_unaligned() {
+        use std::num::NonZeroU32;
+        let mut vec2 = super::Vec2::new(NonZeroU32::new(3).unwrap());
+        for i in 0..7 {
+            vec2.push(i);
+        }
+        let tail = vec2.split_off(4);
+        assert_eq!(vec2.iter().cloned().collect::<Vec<_>>(), vec![0, 1, 2, 3]);
+        assert_eq!(tail.iter().cloned().collect::<Vec<_>>(), vec![4, 5, 6]);
+        assert_eq!(vec2.len(), 4);
+        assert_eq!(tail.len(), 3);
+    }
+
+    #[test]
+    fn test_vec2_split_off_slow_path_leaves_self_usable() {
+        use std::num::NonZeroU32;
+        let mut vec2 = super::Vec2::new(NonZeroU32::new(2).unwrap());
+        vec2.push_front(100);
+        vec2.push(101);
+        // Reserved head room makes this take the slow, element-at-a-time path, which
+        // drains `self` down to empty via `pop`.
+        let tail = vec2.split_off(0);
+        assert_eq!(tail.iter().cloned().collect::<Vec<_>>(), vec![100, 101]);
+        assert!(vec2.is_empty());
+
+        // `self` must come out of that drain in a state that still behaves like a
+        // fresh `Vec2`, not one with stale head-chunk bookkeeping.
+        vec2.push_front(102);
+        assert_eq!(vec2.iter().cloned().collect::<Vec<_>>(), vec![102]);
+    }
+
+    #[test]
+    fn test_vec2_pop_all_reversed_chunks() {
+        use std::num::NonZeroU32;
+        let mut vec2 = super::Vec2::new(NonZeroU32::new(2).unwrap());
+        // Built entirely via push_front, so every chunk is stored back-to-front.
+        for i in (0..5).rev() {
+            vec2.push_front(i);
+        }
+        assert_eq!(
+            vec2.iter().cloned().collect::<Vec<_>>(),
+            vec![0, 1, 2, 3, 4]
+        );
+
+        let mut popped = Vec::new();
+        while let Some(value) = vec2.pop() {
+            popped.push(value);
+        }
+        assert_eq!(popped, vec![4, 3, 2, 1, 0]);
+        assert!(vec2.is_empty());
+
+        // The drain must leave `self` reusable afterward.
+        vec2.push(99);
+        assert_eq!(vec2.iter().cloned().collect::<Vec<_>>(), vec![99]);
+    }
+
+    #[test]
+    fn test_vec2_push_onto_reversed_tail_chunk() {
+        use std::num::NonZeroU32;
+        let mut vec2 = super::Vec2::new(NonZeroU32::new(2).unwrap());
+        // Built entirely via push_front, so the last chunk is still back-to-front,
+        // and it isn't full yet: push() must land the new element at the logical
+        // end, not wherever the chunk's physical end happens to be.
+        vec2.push_front(1);
+        vec2.push_front(0);
+        vec2.push(2);
+        assert_eq!(vec2.iter().cloned().collect::<Vec<_>>(), vec![0, 1, 2]);
+    }
+
+    #[test]
+    fn test_vec2_append_aligned() {
+        use std::num::NonZeroU32;
+        let mut a = super::Vec2::new(NonZeroU32::new(3).unwrap());
+        for i in 0..3 {
+            a.push(i);
+        }
+        let mut b = super::Vec2::new(NonZeroU32::new(3).unwrap());
+        for i in 3..8 {
+            b.push(i);
+        }
+        a.append(&mut b);
+        assert!(b.is_empty());
+        assert_eq!(b.capacity(), 0);
+        assert_eq!(a.len(), 8);
+        assert_eq!(a.capacity(), 9);
+        assert_eq!(
+            a.iter().cloned().collect::<Vec<_>>(),
+            vec![0, 1, 2, 3, 4, 5, 6, 7]
+        );
+    }
+
+    #[test]
+    fn test_vec2_append_unaligned() {
+        use std::num::NonZeroU32;
+        let mut a = super::Vec2::new(NonZeroU32::new(3).unwrap());
+        for i in 0..2 {
+            a.push(i);
+        }
+        let mut b = super::Vec2::new(NonZeroU32::new(3).unwrap());
+        for i in 2..5 {
+            b.push(i);
+        }
+        a.append(&mut b);
+        assert!(b.is_empty());
+        assert_eq!(a.len(), 5);
+        assert_eq!(a.iter().cloned().collect::<Vec<_>>(), vec![0, 1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn test_vec2_from_iter() {
+        let vec2: super::Vec2<i32> = (0..10).collect();
+        assert_eq!(vec2.len(), 10);
+        assert_eq!(
+            vec2.iter().cloned().collect::<Vec<_>>(),
+            (0..10).collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn test_vec2_from_iter_with_chunk_size() {
+        use std::num::NonZeroU32;
+        let vec2 = super::Vec2::from_iter_with_chunk_size(NonZeroU32::new(4).unwrap(), 0..10);
+        assert_eq!(vec2.chunk_size(), 4);
+        assert_eq!(
+            vec2.iter().cloned().collect::<Vec<_>>(),
+            (0..10).collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn test_vec2_extend() {
+        use std::num::NonZeroU32;
+        let mut vec2 = super::Vec2::new(NonZeroU32::new(3).unwrap());
+        vec2.push(0);
+        vec2.extend(1..5);
+        assert_eq!(
+            vec2.iter().cloned().collect::<Vec<_>>(),
+            vec![0, 1, 2, 3, 4]
+        );
+    }
+
+    #[test]
+    fn test_vec2_into_iter_round_trip() {
+        use std::num::NonZeroU32;
+        let mut vec2 = super::Vec2::new(NonZeroU32::new(3).unwrap());
+        for i in 0..7 {
+            vec2.push(i);
+        }
+        let collected: Vec<_> = vec2.into_iter().collect();
+        assert_eq!(collected, (0..7).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn test_vec2_with_capacity() {
+        use std::num::NonZeroU32;
+        let vec2: super::Vec2<i32> = super::Vec2::with_capacity(NonZeroU32::new(4).unwrap(), 10);
+        assert_eq!(vec2.capacity(), 12);
+        assert_eq!(vec2.len(), 0);
+    }
+}
+
+#[cfg(all(test, feature = "serde"))]
+mod serde_tests {
+    use super::serde_impl::Vec2Seed;
+    use serde::de::DeserializeSeed;
+    use std::num::NonZeroU32;
+
+    #[test]
+    fn test_vec2_serde_round_trip() {
+        let mut vec2 = super::Vec2::new(NonZeroU32::new(3).unwrap());
+        for i in 0..7 {
+            vec2.push(i);
+        }
+        let json = serde_json::to_string(&vec2).unwrap();
+        let round_tripped: super::Vec2<i32> = serde_json::from_str(&json).unwrap();
+        assert_eq!(
+            round_tripped.iter().cloned().collect::<Vec<_>>(),
+            (0..7).collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn test_vec2_serde_round_trip_different_chunk_size() {
+        let mut vec2 = super::Vec2::new(NonZeroU32::new(5).unwrap());
+        for i in 0..9 {
+            vec2.push(i);
+        }
+        let json = serde_json::to_string(&vec2).unwrap();
+
+        let mut deserializer = serde_json::Deserializer::from_str(&json);
+        let round_tripped: super::Vec2<i32> = Vec2Seed::new(NonZeroU32::new(2).unwrap())
+            .deserialize(&mut deserializer)
+            .unwrap();
+
+        assert_eq!(round_tripped.chunk_size(), 2);
+        assert_eq!(
+            round_tripped.iter().cloned().collect::<Vec<_>>(),
+            (0..9).collect::<Vec<_>>()
+        );
+    }
 }